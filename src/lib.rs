@@ -3,18 +3,100 @@
 use std::collections::HashSet;
 use std::fmt::Display;
 use std::net::SocketAddr;
+use std::path::PathBuf;
 
 mod platform;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+type Result<T> = std::result::Result<T, ListenersError>;
 
-/// A process listening on a TCP socket.
+/// Errors returned by this crate's listener and connection enumeration functions.
+#[derive(Debug)]
+pub enum ListenersError {
+    /// The current platform has no listener enumeration support at all, as opposed to a
+    /// transient or permission failure. WASI targets report this unconditionally.
+    Unsupported {
+        /// The unsupported platform, e.g. `std::env::consts::OS`.
+        target: &'static str,
+    },
+    /// An I/O error occurred while reading a platform data source (e.g. `/proc`).
+    Io(std::io::Error),
+    /// A call into a platform API (a Windows API, `proc_pidfdinfo`, `sysctl`, ...) failed.
+    PlatformApi {
+        /// The name of the API call that failed.
+        call: &'static str,
+        /// The error code it returned.
+        code: i32,
+    },
+    /// A platform data source was present but didn't parse into the shape this crate expects.
+    Parse(String),
+}
+
+impl Display for ListenersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListenersError::Unsupported { target } => {
+                write!(f, "listener enumeration is not supported on {target}")
+            }
+            ListenersError::Io(err) => write!(f, "I/O error: {err}"),
+            ListenersError::PlatformApi { call, code } => {
+                write!(f, "{call} failed with code {code}")
+            }
+            ListenersError::Parse(message) => write!(f, "failed to parse platform data: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ListenersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ListenersError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ListenersError {
+    fn from(err: std::io::Error) -> Self {
+        ListenersError::Io(err)
+    }
+}
+
+impl From<std::num::TryFromIntError> for ListenersError {
+    fn from(err: std::num::TryFromIntError) -> Self {
+        ListenersError::Parse(err.to_string())
+    }
+}
+
+impl ListenersError {
+    /// Builds a [`ListenersError::PlatformApi`] from the current thread's `errno`, for platform
+    /// calls (`sysctl`, `kinfo_getallproc`, ...) that signal failure via a null/negative return
+    /// and report details through `errno` rather than a direct error code.
+    pub(crate) fn platform_api_from_errno(call: &'static str) -> Self {
+        ListenersError::PlatformApi {
+            call,
+            code: std::io::Error::last_os_error().raw_os_error().unwrap_or(-1),
+        }
+    }
+}
+
+/// A process listening on a socket.
 #[derive(Eq, PartialEq, Hash, Debug)]
 pub struct Listener {
     /// The listening process.
     pub process: Process,
-    /// The TCP socket used by the listener.
+    /// The socket used by the listener.
     pub socket: SocketAddr,
+    /// The transport protocol the listener is bound with.
+    pub protocol: Protocol,
+}
+
+/// The transport protocol used by a [`Listener`].
+#[derive(Eq, PartialEq, Hash, Debug, Copy, Clone)]
+pub enum Protocol {
+    /// TCP.
+    Tcp,
+    /// UDP.
+    Udp,
 }
 
 /// A process, characterized by its PID and name.
@@ -26,6 +108,77 @@ pub struct Process {
     pub name: String,
 }
 
+/// A TCP connection in any state, not just listening.
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct Connection {
+    /// The process that owns the connection.
+    pub process: Process,
+    /// The local end of the connection.
+    pub local: SocketAddr,
+    /// The remote end of the connection.
+    pub remote: SocketAddr,
+    /// The connection's current TCP state.
+    pub state: TcpState,
+}
+
+/// The state of a TCP connection, as reported by the operating system.
+#[derive(Eq, PartialEq, Hash, Debug, Copy, Clone)]
+pub enum TcpState {
+    /// The socket is listening for incoming connections.
+    Listen,
+    /// A `SYN` has been sent, awaiting a matching `SYN`/`ACK`.
+    SynSent,
+    /// A `SYN` has been received and a `SYN`/`ACK` sent.
+    SynReceived,
+    /// The connection is open and data can be exchanged in both directions.
+    Established,
+    /// The local end has closed and is awaiting the remote end's `FIN`.
+    FinWait1,
+    /// The local end's `FIN` has been acknowledged, awaiting the remote `FIN`.
+    FinWait2,
+    /// The remote end has closed; the local end has not yet closed.
+    CloseWait,
+    /// Both ends have closed and are waiting for acknowledgement of the other's `FIN`.
+    Closing,
+    /// The local end has closed after receiving and acknowledging a remote `FIN`.
+    LastAck,
+    /// The local end is waiting to be sure the remote end received the acknowledgement of its `FIN`.
+    TimeWait,
+    /// The connection has been fully closed.
+    Closed,
+    /// A state code reported by the platform that this crate does not (yet) decode.
+    Unknown(u32),
+}
+
+/// A process listening on an `AF_UNIX` domain socket.
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct UnixListener {
+    /// The listening process.
+    pub process: Process,
+    /// The address the socket is bound to.
+    pub address: UnixSocketAddr,
+}
+
+/// The address of a Unix domain socket, following the `unix(7)` address kinds.
+#[derive(Eq, PartialEq, Hash, Debug, Clone)]
+pub enum UnixSocketAddr {
+    /// A socket bound to a filesystem path.
+    Pathname(PathBuf),
+    /// A Linux abstract-namespace socket, identified by the name after the leading NUL byte.
+    Abstract(String),
+    /// A socket with no bound address.
+    Unnamed,
+}
+
+/// A listening endpoint, unifying IP sockets and Unix domain sockets under one type.
+#[derive(Eq, PartialEq, Hash, Debug, Clone)]
+pub enum Endpoint {
+    /// A TCP/UDP socket bound to an IP address and port.
+    Inet(SocketAddr),
+    /// A Unix domain socket.
+    Unix(UnixSocketAddr),
+}
+
 /// Returns all the listeners.
 ///
 /// # Errors
@@ -40,17 +193,49 @@ pub struct Process {
 ///
 /// Output:
 /// ``` text
-/// PID: 1088       Process name: rustrover                 Socket: [::7f00:1]:63342
-/// PID: 609        Process name: Microsoft SharePoint      Socket: [::1]:42050
-/// PID: 160        Process name: mysqld                    Socket: [::]:33060
-/// PID: 160        Process name: mysqld                    Socket: [::]:3306
-/// PID: 460        Process name: rapportd                  Socket: 0.0.0.0:50928
-/// PID: 460        Process name: rapportd                  Socket: [::]:50928
+/// PID: 1088       Process name: rustrover                 Protocol: TCP  Socket: [::7f00:1]:63342
+/// PID: 609        Process name: Microsoft SharePoint      Protocol: TCP  Socket: [::1]:42050
+/// PID: 160        Process name: mysqld                    Protocol: TCP  Socket: [::]:33060
+/// PID: 160        Process name: mysqld                    Protocol: TCP  Socket: [::]:3306
+/// PID: 460        Process name: rapportd                  Protocol: UDP  Socket: 0.0.0.0:50928
+/// PID: 460        Process name: rapportd                  Protocol: UDP  Socket: [::]:50928
 /// ```
 pub fn get_all() -> Result<HashSet<Listener>> {
     platform::get_all()
 }
 
+/// Returns all the listeners using the given transport protocol.
+///
+/// # Errors
+///
+/// This function returns an error if it fails to retrieve listeners for the current platform.
+pub fn get_all_by_protocol(protocol: Protocol) -> Result<HashSet<Listener>> {
+    platform::get_all().map(|listeners| {
+        listeners
+            .into_iter()
+            .filter(|listener| listener.protocol == protocol)
+            .collect()
+    })
+}
+
+/// Returns all TCP connections in any state (listening, established, closing, ...).
+///
+/// # Errors
+///
+/// This function returns an error if it fails to retrieve connections for the current platform.
+pub fn get_connections() -> Result<HashSet<Connection>> {
+    platform::get_connections()
+}
+
+/// Returns all the processes listening on an `AF_UNIX` domain socket.
+///
+/// # Errors
+///
+/// This function returns an error if it fails to retrieve listeners for the current platform.
+pub fn get_unix_listeners() -> Result<HashSet<UnixListener>> {
+    platform::get_unix_listeners()
+}
+
 /// Returns the list of processes listening on a given TCP port.
 ///
 /// # Errors
@@ -77,6 +262,24 @@ pub fn get_processes_by_port(port: u16) -> Result<HashSet<Process>> {
     })
 }
 
+/// Returns the list of processes listening on a given TCP or UDP port with the given protocol.
+///
+/// # Errors
+///
+/// This function returns an error if it fails to retrieve listeners for the current platform.
+pub fn get_processes_by_port_and_protocol(
+    port: u16,
+    protocol: Protocol,
+) -> Result<HashSet<Process>> {
+    platform::get_all().map(|listeners| {
+        listeners
+            .into_iter()
+            .filter(|listener| listener.socket.port() == port && listener.protocol == protocol)
+            .map(|listener| listener.process)
+            .collect()
+    })
+}
+
 /// Returns the list of ports listened to by a process given its PID.
 ///
 /// # Errors
@@ -132,9 +335,18 @@ pub fn get_ports_by_process_name(name: &str) -> Result<HashSet<u16>> {
 }
 
 impl Listener {
-    fn new(pid: u32, name: String, socket: SocketAddr) -> Self {
+    fn new(pid: u32, name: String, socket: SocketAddr, protocol: Protocol) -> Self {
         let process = Process::new(pid, name);
-        Self { process, socket }
+        Self {
+            process,
+            socket,
+            protocol,
+        }
+    }
+
+    /// Returns this listener's bound address as an [`Endpoint`].
+    pub fn endpoint(&self) -> Endpoint {
+        Endpoint::Inet(self.socket)
     }
 }
 
@@ -144,18 +356,109 @@ impl Process {
     }
 }
 
+impl UnixListener {
+    fn new(pid: u32, name: String, address: UnixSocketAddr) -> Self {
+        let process = Process::new(pid, name);
+        Self { process, address }
+    }
+
+    /// Returns this listener's bound address as an [`Endpoint`].
+    pub fn endpoint(&self) -> Endpoint {
+        Endpoint::Unix(self.address.clone())
+    }
+}
+
+impl Connection {
+    fn new(pid: u32, name: String, local: SocketAddr, remote: SocketAddr, state: TcpState) -> Self {
+        let process = Process::new(pid, name);
+        Self {
+            process,
+            local,
+            remote,
+            state,
+        }
+    }
+}
+
 impl Display for Listener {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "PID: {:<10} Process name: {:<25} Socket: {}",
-            self.process.pid, self.process.name, self.socket
+            "PID: {:<10} Process name: {:<25} Protocol: {:<4} Socket: {}",
+            self.process.pid, self.process.name, self.protocol, self.socket
+        )
+    }
+}
+
+impl Display for Protocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Protocol::Tcp => f.pad("TCP"),
+            Protocol::Udp => f.pad("UDP"),
+        }
+    }
+}
+
+impl Display for Connection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PID: {:<10} Process name: {:<25} State: {:<12} Local: {}  Remote: {}",
+            self.process.pid, self.process.name, self.state, self.local, self.remote
         )
     }
 }
 
+impl Display for TcpState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TcpState::Listen => f.pad("LISTEN"),
+            TcpState::SynSent => f.pad("SYN_SENT"),
+            TcpState::SynReceived => f.pad("SYN_RECEIVED"),
+            TcpState::Established => f.pad("ESTABLISHED"),
+            TcpState::FinWait1 => f.pad("FIN_WAIT_1"),
+            TcpState::FinWait2 => f.pad("FIN_WAIT_2"),
+            TcpState::CloseWait => f.pad("CLOSE_WAIT"),
+            TcpState::Closing => f.pad("CLOSING"),
+            TcpState::LastAck => f.pad("LAST_ACK"),
+            TcpState::TimeWait => f.pad("TIME_WAIT"),
+            TcpState::Closed => f.pad("CLOSED"),
+            TcpState::Unknown(code) => f.pad(&format!("UNKNOWN({code})")),
+        }
+    }
+}
+
 impl Display for Process {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "PID: {:<10} Process name: {:<25}", self.pid, self.name)
     }
 }
+
+impl Display for UnixListener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PID: {:<10} Process name: {:<25} Socket: {}",
+            self.process.pid, self.process.name, self.address
+        )
+    }
+}
+
+impl Display for UnixSocketAddr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnixSocketAddr::Pathname(path) => write!(f, "{}", path.display()),
+            UnixSocketAddr::Abstract(name) => write!(f, "@{name}"),
+            UnixSocketAddr::Unnamed => write!(f, "(unnamed)"),
+        }
+    }
+}
+
+impl Display for Endpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Endpoint::Inet(addr) => write!(f, "{addr}"),
+            Endpoint::Unix(addr) => write!(f, "{addr}"),
+        }
+    }
+}
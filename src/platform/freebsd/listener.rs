@@ -0,0 +1,11 @@
+use crate::{Listener, ListenersError};
+
+/// Listener backend for FreeBSD and DragonFlyBSD.
+///
+/// No socket or process table is read on these targets: this always returns
+/// [`ListenersError::Unsupported`] with `std::env::consts::OS` as the target.
+pub(super) fn get_all() -> crate::Result<Vec<Listener>> {
+    Err(ListenersError::Unsupported {
+        target: std::env::consts::OS,
+    })
+}
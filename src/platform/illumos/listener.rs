@@ -0,0 +1,14 @@
+use crate::{Listener, ListenersError};
+
+/// Listener backend for illumos/Solaris.
+///
+/// illumos/Solaris expose the kernel's per-connection table through a
+/// `T_OPTMGMT_REQ`/`T_OPTMGMT_ACK` STREAMS exchange against `/dev/tcp` asking for
+/// `MIB2_TCP_CONN`/`MIB2_TCP6_CONN`. That exchange is not implemented here: this always returns
+/// [`ListenersError::Unsupported`] with `std::env::consts::OS` as the target, rather than an
+/// empty list.
+pub(super) fn get_all() -> crate::Result<Vec<Listener>> {
+    Err(ListenersError::Unsupported {
+        target: std::env::consts::OS,
+    })
+}
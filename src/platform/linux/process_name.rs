@@ -0,0 +1,8 @@
+use std::fs;
+
+/// Reads a process' command name from `/proc/<pid>/comm`.
+pub(super) fn process_name(pid: u32) -> Option<String> {
+    fs::read_to_string(format!("/proc/{pid}/comm"))
+        .ok()
+        .map(|name| name.trim_end().to_string())
+}
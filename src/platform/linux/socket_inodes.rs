@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Walks every process' open file descriptors under `/proc` and returns the owning PID of each
+/// socket inode — the same join `lsof`/`ss -p` perform to attribute a row in `/proc/net/tcp` or
+/// `/proc/net/unix` (both keyed by inode) back to a process.
+pub(super) fn pids_by_socket_inode() -> crate::Result<HashMap<u64, u32>> {
+    let mut owners = HashMap::new();
+    for entry in fs::read_dir("/proc")? {
+        let Ok(entry) = entry else { continue };
+        let Ok(pid) = entry.file_name().to_string_lossy().parse::<u32>() else {
+            continue;
+        };
+        let Ok(fds) = fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+        for fd in fds.flatten() {
+            let Ok(target) = fs::read_link(fd.path()) else {
+                continue;
+            };
+            if let Some(inode) = socket_inode(&target) {
+                owners.entry(inode).or_insert(pid);
+            }
+        }
+    }
+    Ok(owners)
+}
+
+fn socket_inode(link_target: &Path) -> Option<u64> {
+    let name = link_target.to_str()?;
+    let inode = name.strip_prefix("socket:[")?.strip_suffix(']')?;
+    inode.parse().ok()
+}
@@ -0,0 +1,90 @@
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use crate::platform::linux::process_name::process_name;
+use crate::platform::linux::socket_inodes::pids_by_socket_inode;
+use crate::platform::linux::tcp_state::decode_state;
+use crate::{Connection, TcpState};
+
+/// Enumerates TCP connections in any state by parsing `/proc/net/tcp` and `/proc/net/tcp6`, then
+/// joining each row's inode back to the owning process the same way `unix_listener.rs` does for
+/// Unix sockets.
+pub(super) fn get_connections() -> crate::Result<Vec<Connection>> {
+    let mut rows = parse_table("/proc/net/tcp", parse_ipv4)?;
+    rows.extend(parse_table("/proc/net/tcp6", parse_ipv6)?);
+    if rows.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pids = pids_by_socket_inode()?;
+    let mut connections = Vec::new();
+    for (local, remote, state, inode) in rows {
+        let Some(&pid) = pids.get(&inode) else {
+            continue;
+        };
+        if let Some(name) = process_name(pid) {
+            connections.push(Connection::new(pid, name, local, remote, state));
+        }
+    }
+    Ok(connections)
+}
+
+type Row = (SocketAddr, SocketAddr, TcpState, u64);
+
+fn parse_table(path: &str, parse_addr: fn(&str) -> Option<IpAddr>) -> crate::Result<Vec<Row>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut rows = Vec::new();
+    for line in contents.lines().skip(1) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        // sl local_address rem_address st tx_queue:rx_queue tr:tm->when retrnsmt uid timeout inode
+        if fields.len() < 10 {
+            continue;
+        }
+        let Some(local) = parse_endpoint(fields[1], parse_addr) else {
+            continue;
+        };
+        let Some(remote) = parse_endpoint(fields[2], parse_addr) else {
+            continue;
+        };
+        let Ok(inode) = fields[9].parse::<u64>() else {
+            continue;
+        };
+        rows.push((local, remote, decode_state(fields[3]), inode));
+    }
+    Ok(rows)
+}
+
+fn parse_endpoint(field: &str, parse_addr: fn(&str) -> Option<IpAddr>) -> Option<SocketAddr> {
+    let (addr, port) = field.split_once(':')?;
+    Some(SocketAddr::new(
+        parse_addr(addr)?,
+        u16::from_str_radix(port, 16).ok()?,
+    ))
+}
+
+/// `/proc/net/tcp` prints an IPv4 address as the raw bytes of the in-memory `u32`, which on
+/// every target this crate supports is little-endian — the reverse of the dotted-quad byte
+/// order, so each word is byte-swapped before being read as an address.
+fn parse_ipv4(hex: &str) -> Option<IpAddr> {
+    let word = u32::from_str_radix(hex, 16).ok()?;
+    Some(IpAddr::V4(Ipv4Addr::from(word.swap_bytes())))
+}
+
+/// `/proc/net/tcp6` prints an IPv6 address as four 32-bit words in the same reversed-byte layout
+/// as [`parse_ipv4`], concatenated in address order.
+fn parse_ipv6(hex: &str) -> Option<IpAddr> {
+    if hex.len() != 32 {
+        return None;
+    }
+    let mut bytes = [0u8; 16];
+    for (i, chunk) in hex.as_bytes().chunks(8).enumerate() {
+        let word = u32::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok()?;
+        bytes[i * 4..i * 4 + 4].copy_from_slice(&word.swap_bytes().to_be_bytes());
+    }
+    Some(IpAddr::V6(Ipv6Addr::from(bytes)))
+}
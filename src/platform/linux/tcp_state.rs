@@ -0,0 +1,21 @@
+use crate::TcpState;
+
+/// Decodes the hex `st` field of a `/proc/net/tcp`/`/proc/net/tcp6` row into a [`TcpState`].
+/// The values are the kernel's `TCP_*` states from `include/net/tcp_states.h`.
+pub(super) fn decode_state(st: &str) -> TcpState {
+    match u32::from_str_radix(st, 16) {
+        Ok(1) => TcpState::Established,
+        Ok(2) => TcpState::SynSent,
+        Ok(3) => TcpState::SynReceived,
+        Ok(4) => TcpState::FinWait1,
+        Ok(5) => TcpState::FinWait2,
+        Ok(6) => TcpState::TimeWait,
+        Ok(7) => TcpState::Closed,
+        Ok(8) => TcpState::CloseWait,
+        Ok(9) => TcpState::LastAck,
+        Ok(10) => TcpState::Listen,
+        Ok(11) => TcpState::Closing,
+        Ok(other) => TcpState::Unknown(other),
+        Err(_) => TcpState::Unknown(u32::MAX),
+    }
+}
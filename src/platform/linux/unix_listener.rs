@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::platform::linux::process_name::process_name;
+use crate::platform::linux::socket_inodes::pids_by_socket_inode;
+use crate::{Process, UnixListener, UnixSocketAddr};
+
+/// Flag set on a socket's entry in `/proc/net/unix` once `listen(2)` has been called on it.
+///
+/// See `include/net/sock.h`, `SOCK_ACCEPTCON` / `net/unix/af_unix.c`.
+const SO_ACCEPTCON: u32 = 1 << 16;
+
+pub(super) fn get_all() -> crate::Result<Vec<UnixListener>> {
+    let inodes = listening_inodes()?;
+    if inodes.is_empty() {
+        return Ok(Vec::new());
+    }
+    let pids = pids_by_socket_inode()?;
+
+    let mut listeners = Vec::new();
+    for (inode, address) in inodes {
+        let Some(&pid) = pids.get(&inode) else {
+            continue;
+        };
+        if let Some(name) = process_name(pid) {
+            listeners.push(UnixListener::new(pid, name, address));
+        }
+    }
+    Ok(listeners)
+}
+
+/// Parses `/proc/net/unix`, returning the bound address of every socket with `listen(2)` called
+/// on it, keyed by inode.
+fn listening_inodes() -> crate::Result<HashMap<u64, UnixSocketAddr>> {
+    let contents = fs::read_to_string("/proc/net/unix")?;
+    let mut inodes = HashMap::new();
+    for line in contents.lines().skip(1) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        // num refcount protocol flags type st inode [path]
+        let [_num, _refcount, _protocol, flags, _kind, _state, inode, path @ ..] = fields.as_slice()
+        else {
+            continue;
+        };
+        let Ok(flags) = u32::from_str_radix(flags, 16) else {
+            continue;
+        };
+        if flags & SO_ACCEPTCON == 0 {
+            continue;
+        }
+        let Ok(inode) = inode.parse::<u64>() else {
+            continue;
+        };
+        let address = match path.first() {
+            None => UnixSocketAddr::Unnamed,
+            Some(path) if path.starts_with('@') => {
+                UnixSocketAddr::Abstract(path.trim_start_matches('@').to_string())
+            }
+            Some(path) => UnixSocketAddr::Pathname(PathBuf::from(path)),
+        };
+        inodes.insert(inode, address);
+    }
+    Ok(inodes)
+}
@@ -3,21 +3,32 @@ use crate::platform::macos::libproc::proc_pidfdinfo;
 use crate::platform::macos::pid::Pid;
 use crate::platform::macos::socket_fd::SocketFd;
 use crate::platform::macos::statics::PROC_PID_FD_SOCKET_INFO;
+use crate::{ListenersError, Protocol};
 use std::ffi::{c_int, c_void};
 use std::mem;
 use std::mem::MaybeUninit;
 use std::net::{IpAddr, SocketAddr};
 
 #[derive(Debug)]
-pub(super) struct LocalSocket(SocketAddr);
+pub(super) struct LocalSocket {
+    addr: SocketAddr,
+    protocol: Protocol,
+}
 
 impl LocalSocket {
-    pub(super) fn new(addr: IpAddr, port: u16) -> Self {
-        LocalSocket(SocketAddr::new(addr, port))
+    pub(super) fn new(addr: IpAddr, port: u16, protocol: Protocol) -> Self {
+        LocalSocket {
+            addr: SocketAddr::new(addr, port),
+            protocol,
+        }
     }
 
     pub(super) fn socket_addr(&self) -> SocketAddr {
-        self.0
+        self.addr
+    }
+
+    pub(super) fn protocol(&self) -> Protocol {
+        self.protocol
     }
 
     pub(super) fn from_pid_fd(pid: Pid, fd: &SocketFd) -> crate::Result<Self> {
@@ -34,7 +45,10 @@ impl LocalSocket {
         };
 
         if return_code < 0 {
-            return Err("Failed to get file descriptor information".into());
+            return Err(ListenersError::PlatformApi {
+                call: "proc_pidfdinfo",
+                code: return_code,
+            });
         }
 
         let c_socket_fd_info = unsafe { sinfo.assume_init() };
@@ -0,0 +1,42 @@
+use crate::platform::macos::c_socket_fd_info::CSocketFdInfo;
+use crate::platform::macos::libproc::proc_pidfdinfo;
+use crate::platform::macos::pid::Pid;
+use crate::platform::macos::socket_fd::SocketFd;
+use crate::platform::macos::statics::PROC_PID_FD_SOCKET_INFO;
+use crate::{ListenersError, UnixSocketAddr};
+use std::ffi::{c_int, c_void};
+use std::mem;
+use std::mem::MaybeUninit;
+
+#[derive(Debug)]
+pub(super) struct LocalUnixSocket(UnixSocketAddr);
+
+impl LocalUnixSocket {
+    pub(super) fn address(&self) -> &UnixSocketAddr {
+        &self.0
+    }
+
+    pub(super) fn from_pid_fd(pid: Pid, fd: &SocketFd) -> crate::Result<Self> {
+        let mut sinfo: MaybeUninit<CSocketFdInfo> = MaybeUninit::uninit();
+
+        let return_code = unsafe {
+            proc_pidfdinfo(
+                pid.as_c_int(),
+                fd.fd(),
+                PROC_PID_FD_SOCKET_INFO,
+                sinfo.as_mut_ptr().cast::<c_void>(),
+                c_int::try_from(mem::size_of::<CSocketFdInfo>())?,
+            )
+        };
+
+        if return_code < 0 {
+            return Err(ListenersError::PlatformApi {
+                call: "proc_pidfdinfo",
+                code: return_code,
+            });
+        }
+
+        let c_socket_fd_info = unsafe { sinfo.assume_init() };
+        Ok(Self(c_socket_fd_info.to_unix_socket_addr()?))
+    }
+}
@@ -0,0 +1,11 @@
+use crate::{Listener, ListenersError};
+
+/// Listener backend for NetBSD and OpenBSD.
+///
+/// The `kern.file2` sysctl is not read on these targets: this always returns
+/// [`ListenersError::Unsupported`] with `std::env::consts::OS` as the target.
+pub(super) fn get_all() -> crate::Result<Vec<Listener>> {
+    Err(ListenersError::Unsupported {
+        target: std::env::consts::OS,
+    })
+}
@@ -0,0 +1,26 @@
+//! Fallback backend for targets with no platform-specific listener enumeration, e.g. WASI.
+//!
+//! Mirrors the `unsupported()` pattern the standard library's WASI `net.rs` uses: rather than
+//! failing with a generic I/O error, callers get a [`crate::ListenersError::Unsupported`] they
+//! can match on to degrade gracefully.
+use std::collections::HashSet;
+
+use crate::{Connection, Listener, ListenersError, UnixListener};
+
+pub(super) fn get_all() -> crate::Result<HashSet<Listener>> {
+    Err(unsupported())
+}
+
+pub(super) fn get_connections() -> crate::Result<HashSet<Connection>> {
+    Err(unsupported())
+}
+
+pub(super) fn get_unix_listeners() -> crate::Result<HashSet<UnixListener>> {
+    Err(unsupported())
+}
+
+fn unsupported() -> ListenersError {
+    ListenersError::Unsupported {
+        target: std::env::consts::OS,
+    }
+}
@@ -0,0 +1,33 @@
+use std::mem::{size_of, zeroed};
+
+use windows::Win32::Foundation::CloseHandle;
+use windows::Win32::System::Diagnostics::ToolHelp::{
+    CreateToolhelp32Snapshot, Process32First, Process32Next, PROCESSENTRY32, TH32CS_SNAPPROCESS,
+};
+
+pub(super) fn process_name(pid: u32) -> Option<String> {
+    let h = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0).ok()? };
+
+    let mut process = unsafe { zeroed::<PROCESSENTRY32>() };
+    process.dwSize = u32::try_from(size_of::<PROCESSENTRY32>()).ok()?;
+
+    if unsafe { Process32First(h, &mut process) }.is_ok() {
+        loop {
+            if unsafe { Process32Next(h, &mut process) }.is_ok() {
+                let id: u32 = process.th32ProcessID;
+                if id == pid {
+                    break;
+                }
+            } else {
+                return None;
+            }
+        }
+    }
+
+    unsafe { CloseHandle(h).ok()? };
+
+    let name = process.szExeFile;
+    let len = name.iter().position(|&x| x == 0)?;
+
+    String::from_utf8(name[0..len].iter().map(|e| *e as u8).collect()).ok()
+}
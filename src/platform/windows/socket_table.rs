@@ -1,5 +1,5 @@
 use std::ffi::{c_ulong, c_void};
-use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 
 use crate::platform::target_os::c_iphlpapi::GetExtendedTcpTable;
 use crate::platform::target_os::statics::FALSE;
@@ -7,8 +7,11 @@ use crate::platform::target_os::tcp_listener::TcpListener;
 use crate::platform::windows::statics::{
     AF_INET, AF_INET6, ERROR_INSUFFICIENT_BUFFER, LISTEN, NO_ERROR, TCP_TABLE_OWNER_PID_ALL,
 };
+use crate::platform::windows::process_name::process_name;
 use crate::platform::windows::tcp6_table::Tcp6Table;
+use crate::platform::windows::tcp_state::decode_state;
 use crate::platform::windows::tcp_table::TcpTable;
+use crate::{Connection, ListenersError, Protocol};
 
 pub(super) trait SocketTable {
     fn get_table() -> crate::Result<Vec<u8>>;
@@ -16,6 +19,12 @@ pub(super) trait SocketTable {
     fn get_tcp_listener(table: &[u8], index: usize) -> Option<TcpListener>;
 }
 
+/// Tables that expose full connection tuples (not just listening sockets), i.e. the TCP
+/// tables. The UDP tables have no notion of connection state and don't implement this.
+pub(super) trait TcpConnectionTable: SocketTable {
+    fn get_connection(table: &[u8], index: usize) -> Option<Connection>;
+}
+
 impl SocketTable for TcpTable {
     fn get_table() -> crate::Result<Vec<u8>> {
         get_tcp_table(AF_INET)
@@ -37,6 +46,7 @@ impl SocketTable for TcpTable {
                 IpAddr::V4(Ipv4Addr::from(u32::from_be(row.local_addr))),
                 u16::from_be(u16::try_from(row.local_port).ok()?),
                 row.owning_pid,
+                Protocol::Tcp,
             ))
         } else {
             None
@@ -44,6 +54,27 @@ impl SocketTable for TcpTable {
     }
 }
 
+impl TcpConnectionTable for TcpTable {
+    fn get_connection(table: &[u8], index: usize) -> Option<Connection> {
+        #[allow(clippy::cast_ptr_alignment)]
+        let table = unsafe { &*(table.as_ptr().cast::<TcpTable>()) };
+        let rows_ptr = std::ptr::addr_of!(table.rows[0]);
+        let row = unsafe { &*rows_ptr.add(index) };
+        let local = IpAddr::V4(Ipv4Addr::from(u32::from_be(row.local_addr)));
+        let remote = IpAddr::V4(Ipv4Addr::from(u32::from_be(row.remote_addr)));
+        let local_port = u16::from_be(u16::try_from(row.local_port).ok()?);
+        let remote_port = u16::from_be(u16::try_from(row.remote_port).ok()?);
+        let name = process_name(row.owning_pid)?;
+        Some(Connection::new(
+            row.owning_pid,
+            name,
+            SocketAddr::new(local, local_port),
+            SocketAddr::new(remote, remote_port),
+            decode_state(row.state),
+        ))
+    }
+}
+
 impl SocketTable for Tcp6Table {
     fn get_table() -> crate::Result<Vec<u8>> {
         get_tcp_table(AF_INET6)
@@ -65,6 +96,7 @@ impl SocketTable for Tcp6Table {
                 IpAddr::V6(Ipv6Addr::from(row.local_addr)),
                 u16::from_be(u16::try_from(row.local_port).ok()?),
                 row.owning_pid,
+                Protocol::Tcp,
             ))
         } else {
             None
@@ -72,6 +104,27 @@ impl SocketTable for Tcp6Table {
     }
 }
 
+impl TcpConnectionTable for Tcp6Table {
+    fn get_connection(table: &[u8], index: usize) -> Option<Connection> {
+        #[allow(clippy::cast_ptr_alignment)]
+        let table = unsafe { &*(table.as_ptr().cast::<Tcp6Table>()) };
+        let rows_ptr = std::ptr::addr_of!(table.rows[0]);
+        let row = unsafe { &*rows_ptr.add(index) };
+        let local = IpAddr::V6(Ipv6Addr::from(row.local_addr));
+        let remote = IpAddr::V6(Ipv6Addr::from(row.remote_addr));
+        let local_port = u16::from_be(u16::try_from(row.local_port).ok()?);
+        let remote_port = u16::from_be(u16::try_from(row.remote_port).ok()?);
+        let name = process_name(row.owning_pid)?;
+        Some(Connection::new(
+            row.owning_pid,
+            name,
+            SocketAddr::new(local, local_port),
+            SocketAddr::new(remote, remote_port),
+            decode_state(row.state),
+        ))
+    }
+}
+
 fn get_tcp_table(address_family: c_ulong) -> crate::Result<Vec<u8>> {
     let mut table_size: c_ulong = 0;
     let mut err_code = unsafe {
@@ -100,12 +153,18 @@ fn get_tcp_table(address_family: c_ulong) -> crate::Result<Vec<u8>> {
         };
         iterations += 1;
         if iterations > 100 {
-            return Err("Failed to allocate buffer".into());
+            return Err(ListenersError::PlatformApi {
+                call: "GetExtendedTcpTable",
+                code: err_code as i32,
+            });
         }
     }
     if err_code == NO_ERROR {
         Ok(table)
     } else {
-        Err("Failed to get TCP table".into())
+        Err(ListenersError::PlatformApi {
+            call: "GetExtendedTcpTable",
+            code: err_code as i32,
+        })
     }
 }
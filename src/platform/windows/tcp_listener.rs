@@ -1,15 +1,10 @@
-use std::mem::size_of;
-use std::mem::zeroed;
 use std::net::{IpAddr, SocketAddr};
 
-use windows::Win32::Foundation::CloseHandle;
-use windows::Win32::System::Diagnostics::ToolHelp::{
-    CreateToolhelp32Snapshot, Process32First, Process32Next, PROCESSENTRY32, TH32CS_SNAPPROCESS,
-};
-
-use crate::platform::windows::socket_table::SocketTable;
+use crate::platform::windows::process_name::process_name;
+use crate::platform::windows::socket_table::{SocketTable, TcpConnectionTable};
 use crate::platform::windows::tcp6_table::Tcp6Table;
 use crate::platform::windows::tcp_table::TcpTable;
+use crate::Connection;
 use crate::Listener;
 use crate::Protocol;
 
@@ -46,6 +41,23 @@ impl TcpListener {
         Ok(tcp_listeners)
     }
 
+    pub(super) fn get_all_connections() -> crate::Result<Vec<Connection>> {
+        let mut connections = Self::connection_entries::<TcpTable>()?;
+        connections.extend(Self::connection_entries::<Tcp6Table>()?);
+        Ok(connections)
+    }
+
+    fn connection_entries<Table: TcpConnectionTable>() -> crate::Result<Vec<Connection>> {
+        let mut connections = Vec::new();
+        let table = Table::get_table()?;
+        for i in 0..Table::get_rows_count(&table) {
+            if let Some(connection) = Table::get_connection(&table, i) {
+                connections.push(connection);
+            }
+        }
+        Ok(connections)
+    }
+
     pub(super) fn new(local_addr: IpAddr, local_port: u16, pid: u32, protocol: Protocol) -> Self {
         Self {
             local_addr,
@@ -55,38 +67,9 @@ impl TcpListener {
         }
     }
 
-    fn pname(&self) -> Option<String> {
-        let pid = self.pid;
-
-        let h = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0).ok()? };
-
-        let mut process = unsafe { zeroed::<PROCESSENTRY32>() };
-        process.dwSize = u32::try_from(size_of::<PROCESSENTRY32>()).ok()?;
-
-        if unsafe { Process32First(h, &mut process) }.is_ok() {
-            loop {
-                if unsafe { Process32Next(h, &mut process) }.is_ok() {
-                    let id: u32 = process.th32ProcessID;
-                    if id == pid {
-                        break;
-                    }
-                } else {
-                    return None;
-                }
-            }
-        }
-
-        unsafe { CloseHandle(h).ok()? };
-
-        let name = process.szExeFile;
-        let len = name.iter().position(|&x| x == 0)?;
-
-        String::from_utf8(name[0..len].iter().map(|e| *e as u8).collect()).ok()
-    }
-
     pub(super) fn to_listener(&self) -> Option<Listener> {
         let socket = SocketAddr::new(self.local_addr, self.local_port);
-        let pname = self.pname()?;
+        let pname = process_name(self.pid)?;
         Some(Listener::new(self.pid, pname, socket, self.protocol))
     }
 }
@@ -0,0 +1,23 @@
+use std::os::raw::c_ulong;
+
+use crate::TcpState;
+
+/// Decodes a `MIB_TCP_STATE` value into a [`TcpState`].
+///
+/// See the `MIB_TCP_STATE` enumeration in `tcpmib.h`.
+pub(super) fn decode_state(state: c_ulong) -> TcpState {
+    match state {
+        1 => TcpState::Closed,
+        2 => TcpState::Listen,
+        3 => TcpState::SynSent,
+        4 => TcpState::SynReceived,
+        5 => TcpState::Established,
+        6 => TcpState::FinWait1,
+        7 => TcpState::FinWait2,
+        8 => TcpState::CloseWait,
+        9 => TcpState::Closing,
+        10 => TcpState::LastAck,
+        11 => TcpState::TimeWait,
+        other => TcpState::Unknown(other as u32),
+    }
+}